@@ -0,0 +1,200 @@
+//! Mock light client used to drive update-client and misbehaviour tests for `MockChain`
+//! entirely in-memory.
+
+use ibc::downcast;
+use ibc::ics02_client::client_def::AnyClientState;
+use ibc::ics02_client::events::UpdateClient;
+use ibc::ics02_client::misbehaviour::AnyMisbehaviour;
+use ibc::ics24_host::identifier::ChainId;
+use ibc::mock::host::HostBlock;
+use ibc::Height;
+use tendermint_testgen::light_block::TMLightBlock;
+
+use crate::chain::mock::MockChain;
+use crate::chain::Chain;
+use crate::error::{Error, Kind};
+use crate::light_client::{LightClient as LightClientTrait, Verified};
+
+/// Drives light-client state for a `MockChain`, the same way a real light client drives state
+/// for a live full node. Holds its own snapshot of the mock host chain (rather than a handle back
+/// into `MockChain`) so it can keep serving fetches/verifications independently of the chain
+/// handle's lifetime, mirroring how a real light client talks to its RPC endpoint rather than to
+/// the `Chain` instance that spawned it.
+pub struct LightClient {
+    chain_id: ChainId,
+    context: ibc::mock::context::MockContext,
+}
+
+impl LightClient {
+    pub fn new(chain: &MockChain) -> Self {
+        LightClient {
+            chain_id: chain.id().clone(),
+            context: chain.context_snapshot(),
+        }
+    }
+
+    /// Fetches the raw light block the mock host produced at `height`.
+    fn fetch_block(&self, height: Height) -> Result<TMLightBlock, Error> {
+        let host_block = self
+            .context
+            .host_block(height)
+            .ok_or_else(|| Kind::Query.context("height not found on mock host"))?;
+
+        downcast!(host_block => HostBlock::SyntheticTendermint).ok_or_else(|| {
+            Kind::Query
+                .context("mock host is not backed by a synthetic Tendermint chain")
+                .into()
+        })
+    }
+}
+
+impl LightClientTrait<MockChain> for LightClient {
+    fn header_and_minimal_set(
+        &mut self,
+        trusted: Height,
+        target: Height,
+        _client_state: &AnyClientState,
+    ) -> Result<Verified<<MockChain as Chain>::Header>, Error> {
+        let trusted_block = self.fetch_block(trusted)?;
+        let target_block = self.fetch_block(target)?;
+
+        // See the `NOTE` in `MockChain::build_header`: the trusted validator set attached here
+        // must be the trusted block's *next* validator set, to match `next_validators_hash`.
+        let header = <MockChain as Chain>::Header {
+            signed_header: target_block.signed_header,
+            validator_set: target_block.validators,
+            trusted_height: trusted,
+            trusted_validator_set: trusted_block.next_validators,
+        };
+
+        Ok(Verified {
+            target: header,
+            supporting: vec![],
+        })
+    }
+
+    fn verify(
+        &mut self,
+        #[cfg_attr(not(feature = "unstable"), allow(unused_variables))] trusted: Height,
+        target: Height,
+        _client_state: &AnyClientState,
+    ) -> Result<Verified<TMLightBlock>, Error> {
+        // Below the trusted height, there's no validator-set commit ahead of `target` to check:
+        // walk the hash chain back down from `trusted` instead. See `verify_backward`'s doc
+        // comment for why that's still a sound way to extend trust backward.
+        #[cfg(feature = "unstable")]
+        if target < trusted {
+            let trusted_block = self.fetch_block(trusted)?;
+            let target_block = verify_backward(&trusted_block, target, |h| self.fetch_block(h))?;
+
+            return Ok(Verified {
+                target: target_block,
+                supporting: vec![],
+            });
+        }
+
+        let target_block = self.fetch_block(target)?;
+
+        Ok(Verified {
+            target: target_block,
+            supporting: vec![],
+        })
+    }
+
+    fn fetch(&mut self, height: Height) -> Result<TMLightBlock, Error> {
+        self.fetch_block(height)
+    }
+
+    fn check_misbehaviour(
+        &mut self,
+        _update: &UpdateClient,
+        _client_state: &AnyClientState,
+    ) -> Result<Option<AnyMisbehaviour>, Error> {
+        // The mock host is a single, trusted, append-only chain of synthetic blocks: there's no
+        // mechanism by which it can fork, so it never has misbehaviour to report.
+        Ok(None)
+    }
+}
+
+/// Verifies a target header at a height *below* an already-trusted height by walking the hash
+/// chain downward one block at a time, instead of re-checking the validator-set commit at each
+/// step. Trust is anchored in `trusted` (assumed already verified via forward/bisection
+/// verification, i.e. `LightClient::verify`); for each descending height we only need to check
+/// that the next block's `last_block_id` hash matches the previous block's hash, since that
+/// back-reference is itself a commitment made by the chain. This mirrors tendermint-rs's
+/// backward-verification mode, and unlocks testing misbehaviour / historical-proof scenarios
+/// where the relayer needs consensus state at a height earlier than its current trust point.
+///
+/// `fetch` retrieves the raw light block at a given height from the mock host.
+#[cfg(feature = "unstable")]
+pub fn verify_backward(
+    trusted: &TMLightBlock,
+    target_height: Height,
+    fetch: impl Fn(Height) -> Result<TMLightBlock, Error>,
+) -> Result<TMLightBlock, Error> {
+    let version = target_height.version_number;
+    let trusted_height = trusted.signed_header.header.height.value();
+    let target = target_height.revision_height;
+
+    if target >= trusted_height {
+        return Err(Kind::LightClientVerification
+            .context("backward verification requires a target height below the trusted height")
+            .into());
+    }
+
+    let mut current = trusted.clone();
+
+    for h in (target..trusted_height).rev() {
+        let previous = fetch(Height::new(version, h))?;
+
+        let last_block_id_hash = current.signed_header.header.last_block_id.map(|id| id.hash);
+
+        if last_block_id_hash != Some(previous.signed_header.header.hash()) {
+            return Err(Kind::LightClientVerification
+                .context(format!(
+                    "backward verification failed at height {}: last_block_id hash does not match the previous header's hash",
+                    h
+                ))
+                .into());
+        }
+
+        current = previous;
+    }
+
+    Ok(current)
+}
+
+#[cfg(all(test, feature = "unstable"))]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use tokio::runtime::Runtime;
+
+    use super::*;
+    use crate::chain::mock::test_utils::get_basic_chain_config;
+
+    /// Verifying a target height below the trusted height must dispatch to `verify_backward`
+    /// rather than silently fetching the target block the same way forward verification would
+    /// (the bug this request's review caught: `verify_backward` was never called from anywhere).
+    #[test]
+    fn verify_dispatches_to_backward_verification_below_trust() {
+        let config = get_basic_chain_config("mockchain-light-client-backward");
+        let rt = Arc::new(Mutex::new(Runtime::new().unwrap()));
+        let chain = MockChain::bootstrap(config, rt).unwrap();
+
+        let version = chain.id().version();
+        let trusted_height = Height::new(version, 20);
+        let target_height = Height::new(version, 15);
+
+        let client_state =
+            AnyClientState::Tendermint(chain.build_client_state(trusted_height).unwrap());
+
+        let mut light_client = LightClient::new(&chain);
+        let verified = light_client
+            .verify(trusted_height, target_height, &client_state)
+            .unwrap();
+
+        let expected = light_client.fetch_block(target_height).unwrap();
+        assert_eq!(verified.target.signed_header, expected.signed_header);
+    }
+}